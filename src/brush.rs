@@ -0,0 +1,42 @@
+use egui::Rect;
+
+use crate::tile_map::PlacedTile;
+
+// Used in canvas and editor
+pub struct Brush {
+    pub tiles: Vec<BrushTile>,
+    pub rotation: u8, // 0..=3, in 90 degree steps
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+pub struct BrushTile {
+    pub local_offset: (i32, i32),
+    pub uv: Rect,
+}
+impl Brush {
+	// Constants
+	// Constructors
+	// Public functions
+    pub fn rotate(&mut self) {
+        self.rotation = (self.rotation + 1) % 4;
+    }
+    pub fn placed_tile(&self, uv: Rect) -> PlacedTile {
+        PlacedTile {
+            uv,
+            rotation: self.rotation,
+            flip_x: self.flip_x,
+            flip_y: self.flip_y,
+        }
+    }
+	// Private functions
+}
+impl Default for Brush {
+    fn default() -> Self {
+        Self {
+            tiles: Vec::new(),
+            rotation: 0,
+            flip_x: false,
+            flip_y: false,
+        }
+    }
+}
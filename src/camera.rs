@@ -0,0 +1,37 @@
+use egui::Vec2;
+
+// Used in editor
+pub struct Camera {
+    pub zoom: f32,
+    pub pan: Vec2,
+}
+impl Camera {
+	// Constants
+    pub const MIN_ZOOM: f32 = 0.1;
+    pub const MAX_ZOOM: f32 = 16.0;
+	// Constructors
+	// Public functions
+
+    // Rescales around `cursor_relative` (cursor position relative to the canvas' base offset)
+    // so the same world point stays under the cursor after the zoom change.
+    pub fn zoom_to_cursor(&mut self, cursor_relative: Vec2, zoom_delta: f32) {
+        let world_point = (cursor_relative - self.pan) / self.zoom;
+        let new_zoom = (self.zoom * zoom_delta).clamp(Camera::MIN_ZOOM, Camera::MAX_ZOOM);
+
+        self.pan = cursor_relative - world_point * new_zoom;
+        self.zoom = new_zoom;
+    }
+    pub fn recenter(&mut self) {
+        self.zoom = 1.;
+        self.pan = Vec2::ZERO;
+    }
+	// Private functions
+}
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            zoom: 1.,
+            pan: Vec2::ZERO,
+        }
+    }
+}
@@ -1,15 +1,22 @@
-use egui::{Pos2, Vec2};
+use egui::Vec2;
 
 use crate::{
-    tile_map::TileMap, 
-    tile_set::TileSet
+    brush::Brush,
+    camera::Camera,
+    tile_map::TileMap,
+    tile_set::TileSet,
+    tool::Tool
 };
 
 pub struct Canvas {
     pub tile_size: Vec2,
     pub tile_map: TileMap,
     pub tile_set: TileSet,
-    pub selected_rect: Pos2,
+    pub brush: Brush,
+    pub tool: Tool,
+    pub rect_start: Option<(usize, usize)>,
+    pub rect_last_hover: Option<(usize, usize)>,
+    pub camera: Camera,
 }
 impl Canvas {
 	// Constants
@@ -32,7 +39,11 @@ impl Default for Canvas {
             tile_size: Vec2::splat(32.),
             tile_map: TileMap::new(Vec2::splat(8.)),
             tile_set: TileSet::new(Vec2::splat(4.)),
-            selected_rect: Pos2::ZERO,
+            brush: Brush::default(),
+            tool: Tool::default(),
+            rect_start: None,
+            rect_last_hover: None,
+            camera: Camera::default(),
         }
     }
 }
@@ -7,6 +7,9 @@ mod tile_map;
 mod tile_set;
 mod camera;
 mod canvas;
+mod brush;
+mod tool;
+mod project;
 mod ui;
 
 // use tile_map::TileMap;
@@ -0,0 +1,112 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use egui::Rect;
+use serde::{Deserialize, Serialize};
+
+use crate::tile_map::{PlacedTile, TileLayer};
+
+// Serde-friendly mirror of a placed tile, since egui::Rect isn't serde-friendly
+#[derive(Serialize, Deserialize)]
+pub struct TileEntry {
+    pub x: usize,
+    pub y: usize,
+    pub uv_min: (f32, f32),
+    pub uv_max: (f32, f32),
+    pub rotation: u8,
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+impl TileEntry {
+	// Constants
+	// Constructors
+    pub fn from_placed_tile(x: usize, y: usize, tile: &PlacedTile) -> TileEntry {
+        TileEntry {
+            x,
+            y,
+            uv_min: (tile.uv.min.x, tile.uv.min.y),
+            uv_max: (tile.uv.max.x, tile.uv.max.y),
+            rotation: tile.rotation,
+            flip_x: tile.flip_x,
+            flip_y: tile.flip_y,
+        }
+    }
+	// Public functions
+    pub fn uv(&self) -> Rect {
+        Rect::from_min_max(
+            egui::pos2(self.uv_min.0, self.uv_min.1),
+            egui::pos2(self.uv_max.0, self.uv_max.1),
+        )
+    }
+    pub fn placed_tile(&self) -> PlacedTile {
+        PlacedTile {
+            uv: self.uv(),
+            rotation: self.rotation,
+            flip_x: self.flip_x,
+            flip_y: self.flip_y,
+        }
+    }
+	// Private functions
+}
+
+// Serde-friendly mirror of a TileLayer
+#[derive(Serialize, Deserialize)]
+pub struct LayerEntry {
+    pub name: String,
+    pub visible: bool,
+    pub opacity: f32,
+    pub tiles: Vec<TileEntry>,
+}
+impl LayerEntry {
+	// Constants
+	// Constructors
+    pub fn from_layer(layer: &TileLayer) -> LayerEntry {
+        let tiles = layer.tiles.iter()
+            .map(|(&(x, y), tile)| TileEntry::from_placed_tile(x, y, tile))
+            .collect();
+
+        LayerEntry {
+            name: layer.name.clone(),
+            visible: layer.visible,
+            opacity: layer.opacity,
+            tiles,
+        }
+    }
+	// Public functions
+    pub fn to_layer(&self) -> TileLayer {
+        let mut layer = TileLayer::new(self.name.clone());
+        layer.visible = self.visible;
+        layer.opacity = self.opacity;
+        layer.tiles = self.tiles.iter()
+            .map(|entry| ((entry.x, entry.y), entry.placed_tile()))
+            .collect();
+
+        layer
+    }
+	// Private functions
+}
+
+// On-disk representation of a tilemap, saved/loaded from the side panel
+#[derive(Serialize, Deserialize)]
+pub struct Project {
+    pub map_size: (f32, f32),
+    pub tile_size: (f32, f32),
+    pub tileset_path: Option<PathBuf>,
+    pub layers: Vec<LayerEntry>,
+    pub active_layer: usize,
+}
+impl Project {
+	// Constants
+	// Constructors
+	// Public functions
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let contents = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .expect("Failed to serialize project");
+        fs::write(path, contents)
+    }
+    pub fn load(path: &Path) -> std::io::Result<Project> {
+        let contents = fs::read_to_string(path)?;
+        ron::from_str(&contents).map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))
+    }
+	// Private functions
+}
@@ -1,24 +1,107 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use egui::{Rect, Vec2};
 
+// A single stamped tile, transformed by 90-degree rotation steps and/or flips
+#[derive(Clone, Copy, PartialEq)]
+pub struct PlacedTile {
+    pub uv: Rect,
+    pub rotation: u8, // 0..=3, in 90 degree steps
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+
+// A single stacked layer, e.g. ground/decoration/collision
+pub struct TileLayer {
+    pub name: String,
+    pub visible: bool,
+    pub opacity: f32,
+    pub tiles: HashMap<(usize, usize), PlacedTile>,
+}
+impl TileLayer {
+	// Constants
+	// Constructors
+    pub fn new(name: impl Into<String>) -> TileLayer {
+        TileLayer {
+            name: name.into(),
+            visible: true,
+            opacity: 1.,
+            tiles: HashMap::new(),
+        }
+    }
+	// Public functions
+	// Private functions
+}
+
 // Used in editor
 pub struct TileMap {
     pub size: Vec2,
-    pub selected_rect: Option<Rect>,
-    pub tiles: HashMap<(usize, usize), egui::Rect>,
+    pub layers: Vec<TileLayer>,
+    pub active_layer: usize,
 }
 impl TileMap {
 	// Constants
 	// Constructors
     pub fn new(size: Vec2) -> TileMap
     {
-        TileMap { 
+        TileMap {
             size,
-            selected_rect: None,
-            tiles: HashMap::new(),
+            layers: vec![TileLayer::new("Layer 1")],
+            active_layer: 0,
         }
     }
 	// Public functions
+    pub fn active_tiles(&self) -> &HashMap<(usize, usize), PlacedTile> {
+        &self.layers[self.active_layer].tiles
+    }
+    pub fn active_tiles_mut(&mut self) -> &mut HashMap<(usize, usize), PlacedTile> {
+        &mut self.layers[self.active_layer].tiles
+    }
+    pub fn fill_rect(&mut self, start: (usize, usize), end: (usize, usize), tile: PlacedTile) {
+        let (x0, x1) = (start.0.min(end.0), start.0.max(end.0));
+        let (y0, y1) = (start.1.min(end.1), start.1.max(end.1));
+
+        let tiles = self.active_tiles_mut();
+        for y in y0..=y1 {
+            for x in x0..=x1 {
+                tiles.insert((x, y), tile);
+            }
+        }
+    }
+    pub fn flood_fill(&mut self, start: (usize, usize), tile: PlacedTile) {
+        let width = self.size.x as usize;
+        let height = self.size.y as usize;
+        if start.0 >= width || start.1 >= height {
+            return;
+        }
+
+        let target = self.active_tiles().get(&start).map(|tile| tile.uv);
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(start);
+        queue.push_back(start);
+
+        while let Some((x, y)) = queue.pop_front() {
+            if self.active_tiles().get(&(x, y)).map(|tile| tile.uv) != target {
+                continue;
+            }
+            self.active_tiles_mut().insert((x, y), tile);
+
+            let neighbors = [
+                (x.checked_sub(1), Some(y)),
+                (x.checked_add(1), Some(y)),
+                (Some(x), y.checked_sub(1)),
+                (Some(x), y.checked_add(1)),
+            ];
+
+            for (nx, ny) in neighbors {
+                if let (Some(nx), Some(ny)) = (nx, ny) {
+                    if nx < width && ny < height && visited.insert((nx, ny)) {
+                        queue.push_back((nx, ny));
+                    }
+                }
+            }
+        }
+    }
 	// Private functions
-}
\ No newline at end of file
+}
@@ -1,16 +1,22 @@
+use std::path::PathBuf;
+
 use egui::Vec2;
 // used in sidebar
 pub struct TileSet {
     pub size: Vec2,
 	pub texture: Option<egui::TextureHandle>,
+	pub drag_start: Option<(i32, i32)>,
+	pub source_path: Option<PathBuf>,
 }
 impl TileSet {
 	// Constants
 	// Constructors
 	pub fn new(size: Vec2) -> TileSet {
-        TileSet { 
+        TileSet {
 			size,
 			texture: None,
+			drag_start: None,
+			source_path: None,
 		}
     }
 	// Public functions
@@ -0,0 +1,13 @@
+// Used in canvas and editor
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tool {
+    Brush,
+    Rectangle,
+    Fill,
+    Erase,
+}
+impl Default for Tool {
+    fn default() -> Self {
+        Tool::Brush
+    }
+}
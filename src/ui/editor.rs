@@ -1,55 +1,223 @@
-use egui::Color32;
+use egui::{epaint::Vertex, Color32, Key, Mesh, PointerButton, Rect};
 
+use crate::tile_map::PlacedTile;
+use crate::tool::Tool;
 use crate::OxyTiles;
 
 pub fn show(context: &egui::Context, app: &mut OxyTiles) {
+    context.input(|i| {
+        if i.key_pressed(Key::R) {
+            app.canvas.brush.rotate();
+        }
+        if i.key_pressed(Key::X) {
+            app.canvas.brush.flip_x = !app.canvas.brush.flip_x;
+        }
+        if i.key_pressed(Key::Y) {
+            app.canvas.brush.flip_y = !app.canvas.brush.flip_y;
+        }
+    });
+
     egui::CentralPanel::default().show(context, |ui| {
-        let tile_size = app.canvas.tile_size;
+        let tile_size = app.canvas.get_tile_size(app.canvas.camera.zoom);
 
-        let (_response, painter) = ui.allocate_painter(
-            app.canvas.get_world_size(),
-            egui::Sense::click()
+        let (response, painter) = ui.allocate_painter(
+            ui.available_size(),
+            egui::Sense::click_and_drag()
         );
 
-        // Calculate centered offset to prevent cropping
-        let canvas_size = app.canvas.get_world_size();
+        // Calculate the centered offset for an unscaled map, then apply zoom/pan on top of it
+        let unscaled_canvas_size = app.canvas.get_world_size();
         let available_size = painter.clip_rect().size();
-        let offset = painter.clip_rect().min.to_vec2() + (available_size - canvas_size) * 0.5;
+        let offset = painter.clip_rect().min.to_vec2() + (available_size - unscaled_canvas_size) * 0.5
+            + app.canvas.camera.pan;
 
-        for y in 0..app.canvas.tile_map.size.y as usize {
-            for x in 0..app.canvas.tile_map.size.x as usize {
-                let x_pos = offset.x + (x as f32 * tile_size.x);
-                let y_pos = offset.y + (y as f32 * tile_size.y);
+        if let Some(pointer) = response.hover_pos() {
+            let scroll_delta = context.input(|i| i.smooth_scroll_delta.y);
+            if scroll_delta != 0. {
+                let cursor_relative = pointer.to_vec2() - (offset - app.canvas.camera.pan);
+                let zoom_delta = (scroll_delta * 0.002).exp();
+                app.canvas.camera.zoom_to_cursor(cursor_relative, zoom_delta);
+            }
+        }
 
-                let rect = egui::Rect::from_min_size(
-                    egui::pos2(x_pos, y_pos),
-                    tile_size,
-                );
+        if response.dragged_by(PointerButton::Middle) {
+            app.canvas.camera.pan += response.drag_delta();
+        }
 
-                if ((x + y) % 2) == 0 {
-                    painter.rect_filled(rect, egui::CornerRadius::ZERO, egui::Color32::from_rgb(169,169,169));
-                } else{
-                    painter.rect_filled(rect, egui::CornerRadius::ZERO, egui::Color32::from_rgb(84,84,84));
-                }
+        let map_width = app.canvas.tile_map.size.x as usize;
+        let map_height = app.canvas.tile_map.size.y as usize;
+
+        let cell_rect = |x: usize, y: usize| {
+            egui::Rect::from_min_size(
+                egui::pos2(offset.x + (x as f32 * tile_size.x), offset.y + (y as f32 * tile_size.y)),
+                tile_size,
+            )
+        };
 
-                if let Some(uv) = app.canvas.tile_map.tiles.get(&(x, y)) {
-                    if let Some(texture) = &app.canvas.tile_set.texture {
-                        painter.image(texture.id(), rect, *uv, Color32::WHITE);
-                    };
+        // Pre-paint hitbox pass: resolve which cell the pointer is over against this frame's
+        // geometry before anything is painted, instead of sensing each cell as it's drawn
+        let hovered_cell = response.hover_pos().and_then(|pointer| {
+            let local = pointer - offset;
+            if local.x < 0. || local.y < 0. {
+                return None;
+            }
+
+            let (gx, gy) = ((local.x / tile_size.x) as usize, (local.y / tile_size.y) as usize);
+            if gx >= map_width || gy >= map_height {
+                return None;
+            }
+
+            Some((gx, gy))
+        });
+
+        // Checkerboard background
+        for y in 0..map_height {
+            for x in 0..map_width {
+                let color = if (x + y) % 2 == 0 {
+                    egui::Color32::from_rgb(169, 169, 169)
+                } else {
+                    egui::Color32::from_rgb(84, 84, 84)
                 };
+                painter.rect_filled(cell_rect(x, y), egui::CornerRadius::ZERO, color);
+            }
+        }
 
-                let response = ui.interact(rect, ui.id().with((x, y)), egui::Sense::click());
-                if response.clicked() {
-                    if let Some(selected_uv) = app.canvas.tile_map.selected_rect {
-                        if let Some(texture) = &app.canvas.tile_set.texture {
-                            app.canvas.tile_set.texture = Some(texture.clone());
-                            app.canvas.tile_map.tiles.insert((x, y), selected_uv);
-                        }
+        // Layers, bottom to top, skipping hidden ones
+        if let Some(texture) = &app.canvas.tile_set.texture {
+            for layer in &app.canvas.tile_map.layers {
+                if !layer.visible {
+                    continue;
+                }
+                let tint = layer_tint(layer.opacity);
+                for (&(x, y), placed_tile) in &layer.tiles {
+                    painter.add(placed_tile_mesh(cell_rect(x, y), placed_tile, texture.id(), tint));
+                }
+            }
+        }
+
+        // Ghost preview of the brush footprint under the cursor, resolved once against this
+        // frame's geometry so it can't lag a frame behind when panned/zoomed/resized
+        if let (Some((hx, hy)), Some(texture)) = (hovered_cell, &app.canvas.tile_set.texture) {
+            if app.canvas.tool == Tool::Brush {
+                let ghost_tint = Color32::from_white_alpha(128);
+                for brush_tile in &app.canvas.brush.tiles {
+                    if let Some((tx, ty)) = offset_cell(hx, hy, brush_tile.local_offset, map_width, map_height) {
+                        let placed_tile = app.canvas.brush.placed_tile(brush_tile.uv);
+                        painter.add(placed_tile_mesh(cell_rect(tx, ty), &placed_tile, texture.id(), ghost_tint));
                     }
                 }
+            }
+        }
 
+        if let Some((x, y)) = hovered_cell {
+            match app.canvas.tool {
+                Tool::Brush => {
+                    if response.clicked_by(PointerButton::Primary) || response.dragged_by(PointerButton::Primary) {
+                        for brush_tile in &app.canvas.brush.tiles {
+                            if let Some((tx, ty)) = offset_cell(x, y, brush_tile.local_offset, map_width, map_height) {
+                                if app.canvas.tile_set.texture.is_some() {
+                                    let placed_tile = app.canvas.brush.placed_tile(brush_tile.uv);
+                                    app.canvas.tile_map.active_tiles_mut().insert((tx, ty), placed_tile);
+                                }
+                            }
+                        }
+                    }
+                }
+                Tool::Erase => {
+                    if response.clicked_by(PointerButton::Primary) || response.dragged_by(PointerButton::Primary) {
+                        app.canvas.tile_map.active_tiles_mut().remove(&(x, y));
+                    }
+                }
+                Tool::Rectangle => {
+                    if response.drag_started_by(PointerButton::Primary) {
+                        app.canvas.rect_start = Some((x, y));
+                    }
+                    app.canvas.rect_last_hover = Some((x, y));
+                }
+                Tool::Fill => {
+                    if response.clicked_by(PointerButton::Primary) {
+                        if let Some(uv) = app.canvas.brush.tiles.first().map(|tile| tile.uv) {
+                            let placed_tile = app.canvas.brush.placed_tile(uv);
+                            app.canvas.tile_map.flood_fill((x, y), placed_tile);
+                        }
+                    }
+                }
+            }
+        }
 
-            };
-        };
+        // Commit the rectangle on release even if the drag ended outside the map, using the
+        // last in-bounds cell seen during the drag rather than dropping it silently
+        if app.canvas.tool == Tool::Rectangle && response.drag_stopped() {
+            if let Some(start) = app.canvas.rect_start.take() {
+                if let Some(end) = app.canvas.rect_last_hover {
+                    if let Some(uv) = app.canvas.brush.tiles.first().map(|tile| tile.uv) {
+                        let placed_tile = app.canvas.brush.placed_tile(uv);
+                        app.canvas.tile_map.fill_rect(start, end, placed_tile);
+                    }
+                }
+            }
+        }
     });
-}
\ No newline at end of file
+}
+
+// Tints a layer's tiles by its opacity
+fn layer_tint(opacity: f32) -> Color32 {
+    Color32::from_white_alpha((opacity.clamp(0., 1.) * 255.) as u8)
+}
+
+// Resolves a brush offset relative to the hovered cell, skipping cells that fall outside the map
+fn offset_cell(x: usize, y: usize, local_offset: (i32, i32), map_width: usize, map_height: usize) -> Option<(usize, usize)> {
+    let tx = x as i32 + local_offset.0;
+    let ty = y as i32 + local_offset.1;
+
+    if tx < 0 || ty < 0 {
+        return None;
+    }
+
+    let (tx, ty) = (tx as usize, ty as usize);
+    if tx >= map_width || ty >= map_height {
+        return None;
+    }
+
+    Some((tx, ty))
+}
+
+// painter.image can't rotate/flip, so build the quad by hand and permute its UVs
+fn placed_tile_mesh(rect: Rect, tile: &PlacedTile, texture_id: egui::TextureId, tint: Color32) -> Mesh {
+    let mut mesh = Mesh::with_texture(texture_id);
+
+    let screen_corners = [
+        rect.left_top(),
+        rect.right_top(),
+        rect.right_bottom(),
+        rect.left_bottom(),
+    ];
+
+    let mut uv_corners = [
+        tile.uv.left_top(),
+        tile.uv.right_top(),
+        tile.uv.right_bottom(),
+        tile.uv.left_bottom(),
+    ];
+
+    if tile.flip_x {
+        uv_corners.swap(0, 1);
+        uv_corners.swap(2, 3);
+    }
+    if tile.flip_y {
+        uv_corners.swap(0, 3);
+        uv_corners.swap(1, 2);
+    }
+    uv_corners.rotate_left((tile.rotation % 4) as usize);
+
+    for i in 0..4 {
+        mesh.vertices.push(Vertex {
+            pos: screen_corners[i],
+            uv: uv_corners[i],
+            color: tint,
+        });
+    }
+    mesh.indices.extend_from_slice(&[0, 1, 2, 0, 2, 3]);
+
+    mesh
+}
@@ -1,3 +1,9 @@
+use std::path::Path;
+
+use crate::brush::BrushTile;
+use crate::project::{LayerEntry, Project};
+use crate::tile_map::TileLayer;
+use crate::tool::Tool;
 use crate::OxyTiles;
 use eframe::egui;
 use egui::{
@@ -6,49 +12,175 @@ use egui::{
 
 pub fn show(context: &egui::Context, app: &mut OxyTiles) {
     egui::SidePanel::right("side_panel").show(context, |ui| {
-        if let Some(texture) = &app.canvas.tile_set.texture {
-            let response = ui.add(egui::Image::new(texture).sense(egui::Sense::click()));
-            if let Some(hover_pos) = response.hover_pos() {
-                let local_pos = hover_pos - response.rect.min;
-                let tile_size = 16.;
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut app.canvas.tool, Tool::Brush, "Brush");
+            ui.selectable_value(&mut app.canvas.tool, Tool::Rectangle, "Rectangle");
+            ui.selectable_value(&mut app.canvas.tool, Tool::Fill, "Fill");
+            ui.selectable_value(&mut app.canvas.tool, Tool::Erase, "Erase");
+        });
+        ui.separator();
 
-                let grid = Vec2::new(
-                    (local_pos.x / tile_size).floor(),
-                    (local_pos.y / tile_size).floor(),
-                );
+        ui.horizontal(|ui| {
+            ui.label(format!("Zoom: {:.0}%", app.canvas.camera.zoom * 100.));
+            if ui.button("Recenter").clicked() {
+                app.canvas.camera.recenter();
+            }
+        });
+        ui.separator();
 
-                let snap_pos = response.rect.min + Vec2::new(
-                    grid.x * tile_size, 
-                    grid.y * tile_size, 
-                );
+        ui.horizontal(|ui| {
+            if ui.button("Save").clicked() {
+                if let Some(path) = rfd::FileDialog::new().add_filter("project", &["ron"]).save_file() {
+                    let layers = app.canvas.tile_map.layers.iter().map(LayerEntry::from_layer).collect();
 
-                let rect = Rect::from_min_size(
-                    snap_pos, 
-                    Vec2::new(tile_size, tile_size)
+                    let project = Project {
+                        map_size: (app.canvas.tile_map.size.x, app.canvas.tile_map.size.y),
+                        tile_size: (app.canvas.tile_size.x, app.canvas.tile_size.y),
+                        tileset_path: app.canvas.tile_set.source_path.clone(),
+                        layers,
+                        active_layer: app.canvas.tile_map.active_layer,
+                    };
+
+                    project.save(&path).expect("Failed to save project");
+                };
+            }
+            if ui.button("Open").clicked() {
+                if let Some(path) = rfd::FileDialog::new().add_filter("project", &["ron"]).pick_file() {
+                    let project = Project::load(&path).expect("Failed to load project");
+
+                    if let Some(tileset_path) = project.tileset_path.clone() {
+                        app.canvas.tile_set.texture = Some(load_tileset_texture(context, &tileset_path));
+                        app.canvas.tile_set.source_path = Some(tileset_path);
+                    }
+
+                    app.canvas.tile_map.size = Vec2::new(project.map_size.0, project.map_size.1);
+                    app.canvas.tile_size = Vec2::new(project.tile_size.0, project.tile_size.1);
+                    app.canvas.tile_map.layers = project.layers.iter().map(LayerEntry::to_layer).collect();
+                    app.canvas.tile_map.active_layer = project.active_layer.min(app.canvas.tile_map.layers.len().saturating_sub(1));
+                };
+            }
+        });
+        ui.separator();
+
+        ui.label("Layers");
+        let layer_count = app.canvas.tile_map.layers.len();
+        let mut layer_to_remove = None;
+        for i in 0..layer_count {
+            ui.horizontal(|ui| {
+                let is_active = app.canvas.tile_map.active_layer == i;
+                if ui.selectable_label(is_active, &app.canvas.tile_map.layers[i].name).clicked() {
+                    app.canvas.tile_map.active_layer = i;
+                }
+                ui.checkbox(&mut app.canvas.tile_map.layers[i].visible, "");
+                ui.add(
+                    egui::Slider::new(&mut app.canvas.tile_map.layers[i].opacity, 0. ..=1.)
+                        .show_value(false)
                 );
+                if ui.small_button("^").clicked() && i > 0 {
+                    app.canvas.tile_map.layers.swap(i, i - 1);
+                    if app.canvas.tile_map.active_layer == i {
+                        app.canvas.tile_map.active_layer = i - 1;
+                    } else if app.canvas.tile_map.active_layer == i - 1 {
+                        app.canvas.tile_map.active_layer = i;
+                    }
+                }
+                if ui.small_button("v").clicked() && i + 1 < layer_count {
+                    app.canvas.tile_map.layers.swap(i, i + 1);
+                    if app.canvas.tile_map.active_layer == i {
+                        app.canvas.tile_map.active_layer = i + 1;
+                    } else if app.canvas.tile_map.active_layer == i + 1 {
+                        app.canvas.tile_map.active_layer = i;
+                    }
+                }
+                if ui.small_button("x").clicked() && layer_count > 1 {
+                    layer_to_remove = Some(i);
+                }
+            });
+        }
+        if let Some(index) = layer_to_remove {
+            app.canvas.tile_map.layers.remove(index);
+            app.canvas.tile_map.active_layer = app.canvas.tile_map.active_layer.min(app.canvas.tile_map.layers.len() - 1);
+        }
+        if ui.button("Add layer").clicked() {
+            let name = format!("Layer {}", app.canvas.tile_map.layers.len() + 1);
+            app.canvas.tile_map.layers.push(TileLayer::new(name));
+            app.canvas.tile_map.active_layer = app.canvas.tile_map.layers.len() - 1;
+        }
+        ui.separator();
 
-                ui.painter().rect_stroke(
-                    rect, 
-                    CornerRadius::ZERO,
-                    Stroke::new(1., Color32::RED),
-                    egui::StrokeKind::Outside
+        if let Some(texture) = &app.canvas.tile_set.texture {
+            let response = ui.add(egui::Image::new(texture).sense(egui::Sense::click_and_drag()));
+            let tile_size = 16.;
+            let texture_size = texture.size_vec2();
+
+            if let Some(hover_pos) = response.hover_pos() {
+                let local_pos = hover_pos - response.rect.min;
+
+                let grid = (
+                    (local_pos.x / tile_size).floor() as i32,
+                    (local_pos.y / tile_size).floor() as i32,
                 );
 
                 if response.clicked_by(PointerButton::Primary) {
-                    app.canvas.selected_rect = snap_pos;
-                    let texture_size = texture.size_vec2();
-    
                     let uv_min = egui::pos2(
-                        (grid.x * tile_size) / texture_size.x,
-                        (grid.y * tile_size) / texture_size.y,
+                        (grid.0 as f32 * tile_size) / texture_size.x,
+                        (grid.1 as f32 * tile_size) / texture_size.y,
                     );
                     let uv_max = egui::pos2(
-                        ((grid.x + 1.0) * tile_size) / texture_size.x,
-                        ((grid.y + 1.0) * tile_size) / texture_size.y,
+                        ((grid.0 + 1) as f32 * tile_size) / texture_size.x,
+                        ((grid.1 + 1) as f32 * tile_size) / texture_size.y,
                     );
 
-                    app.canvas.tile_map.selected_rect = Some(Rect::from_min_max(uv_min, uv_max));
-                };
+                    app.canvas.brush.tiles = vec![BrushTile {
+                        local_offset: (0, 0),
+                        uv: Rect::from_min_max(uv_min, uv_max),
+                    }];
+                }
+
+                if response.drag_started_by(PointerButton::Primary) {
+                    app.canvas.tile_set.drag_start = Some(grid);
+                }
+
+                if let Some(start) = app.canvas.tile_set.drag_start {
+                    let (x0, x1) = (start.0.min(grid.0), start.0.max(grid.0));
+                    let (y0, y1) = (start.1.min(grid.1), start.1.max(grid.1));
+
+                    let rect = Rect::from_min_max(
+                        response.rect.min + Vec2::new(x0 as f32 * tile_size, y0 as f32 * tile_size),
+                        response.rect.min + Vec2::new((x1 + 1) as f32 * tile_size, (y1 + 1) as f32 * tile_size),
+                    );
+
+                    ui.painter().rect_stroke(
+                        rect,
+                        CornerRadius::ZERO,
+                        Stroke::new(1., Color32::RED),
+                        egui::StrokeKind::Outside
+                    );
+
+                    if response.drag_stopped() {
+                        let mut tiles = Vec::new();
+                        for gy in y0..=y1 {
+                            for gx in x0..=x1 {
+                                let uv_min = egui::pos2(
+                                    (gx as f32 * tile_size) / texture_size.x,
+                                    (gy as f32 * tile_size) / texture_size.y,
+                                );
+                                let uv_max = egui::pos2(
+                                    ((gx + 1) as f32 * tile_size) / texture_size.x,
+                                    ((gy + 1) as f32 * tile_size) / texture_size.y,
+                                );
+
+                                tiles.push(BrushTile {
+                                    local_offset: (gx - x0, gy - y0),
+                                    uv: Rect::from_min_max(uv_min, uv_max),
+                                });
+                            }
+                        }
+
+                        app.canvas.brush.tiles = tiles;
+                        app.canvas.tile_set.drag_start = None;
+                    }
+                }
             };
         } else {
             ui.label("No texture have been loaded");
@@ -57,17 +189,22 @@ pub fn show(context: &egui::Context, app: &mut OxyTiles) {
                     .add_filter("image", &["png", "jpg", "jpeg"])
                     .pick_file()
                 {
-                    let image = image::open(path).expect("Invalid path");
-    
-                    app.canvas.tile_set.texture = Some(context.load_texture("sidebar-texture", 
-                        egui::ColorImage::from_rgba_unmultiplied(
-                            [image.width() as _, image.height() as _],
-                            image.to_rgba8().as_flat_samples().as_slice(),
-                        ),
-                        egui::TextureOptions::NEAREST
-                    ));
+                    app.canvas.tile_set.texture = Some(load_tileset_texture(context, &path));
+                    app.canvas.tile_set.source_path = Some(path);
                 };
             };
         };
     });
-}
\ No newline at end of file
+}
+
+fn load_tileset_texture(context: &egui::Context, path: &Path) -> egui::TextureHandle {
+    let image = image::open(path).expect("Invalid path");
+
+    context.load_texture("sidebar-texture",
+        egui::ColorImage::from_rgba_unmultiplied(
+            [image.width() as _, image.height() as _],
+            image.to_rgba8().as_flat_samples().as_slice(),
+        ),
+        egui::TextureOptions::NEAREST
+    )
+}